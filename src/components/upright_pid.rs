@@ -0,0 +1,108 @@
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::prelude::{default, Component, GlobalTransform, Query, Res, Vec3};
+use bevy::reflect::Reflect;
+use bevy_rapier3d::prelude::RapierContext;
+
+use crate::controller::Gravity;
+
+/// Optional PID-based upright/orientation stabilization, layered on top of
+/// [`ControllerSettings::upright_spring`](super::settings::ControllerSettings::upright_spring).
+///
+/// The spring alone is a pure proportional-derivative controller, which
+/// leaves a steady-state lean error for things like the `starship` preset or
+/// a self-balancing bike sitting on a slope. Adding this component's `ki`
+/// term accumulates that error away over time.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct UprightPid {
+    /// Proportional gain, mirroring `upright_spring.strength`.
+    pub kp: f32,
+    /// Integral gain. Accumulated error is multiplied by this every step to
+    /// produce the torque that cancels steady-state lean.
+    pub ki: f32,
+    /// Derivative gain, mirroring `upright_spring.damping`.
+    pub kd: f32,
+    /// Accumulated orientation error, in axis-angle form (`axis * angle`).
+    pub integral: Vec3,
+    /// The error computed on the previous step, used for the derivative term.
+    pub prev_error: Vec3,
+    /// Multiplied into `integral` every step, before accumulating the new
+    /// error, to bleed off windup.
+    pub decay: f32,
+    /// The maximum length `integral` is allowed to reach, to prevent runaway
+    /// accumulation while upside-down or stuck against geometry.
+    pub max_integral: f32,
+}
+
+impl Default for UprightPid {
+    fn default() -> Self {
+        Self {
+            kp: default(),
+            ki: default(),
+            kd: default(),
+            integral: Vec3::ZERO,
+            prev_error: Vec3::ZERO,
+            decay: 1.0,
+            max_integral: f32::MAX,
+        }
+    }
+}
+
+impl UprightPid {
+    /// Step the controller given this frame's axis-angle orientation error
+    /// (current up vs. target up), returning the torque to apply.
+    pub fn update(&mut self, error: Vec3, dt: f32) -> Vec3 {
+        self.integral = (self.integral * self.decay + error * dt)
+            .clamp_length_max(self.max_integral);
+
+        let derivative = if dt > 0.0 {
+            (error - self.prev_error) / dt
+        } else {
+            Vec3::ZERO
+        };
+        self.prev_error = error;
+
+        self.kp * error + self.ki * self.integral + self.kd * derivative
+    }
+}
+
+/// Calculated torque from [`UprightPid`], accumulated into the controller's
+/// applied force alongside `upright_spring`.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct UprightForce {
+    /// Angular impulse to apply to rotate the character upright.
+    pub angular: Vec3,
+}
+
+/// Calculate the PID upright-stabilization torque for the controller.
+pub fn upright_pid_force(
+    mut query: Query<(
+        &mut UprightForce,
+        &mut UprightPid,
+        &GlobalTransform,
+        &Gravity,
+    )>,
+    ctx: Res<RapierContext>,
+) {
+    let dt = ctx.integration_parameters.dt;
+    for (mut force, mut pid, global, gravity) in &mut query {
+        let current_up = global.compute_transform().rotation * Vec3::Y;
+        let target_up = gravity.up_vector.try_normalize().unwrap_or(Vec3::Y);
+
+        let angle = current_up.dot(target_up).clamp(-1.0, 1.0).acos();
+        // `cross` degenerates to (near-)zero both when the vectors are
+        // parallel (angle ~= 0, no correction needed anyway) and when they're
+        // anti-parallel (angle ~= PI, fully upside-down) — the case that
+        // actually matters, since that's when recovery torque is most
+        // needed. Fall back to an arbitrary axis orthogonal to `current_up`
+        // so windup still produces torque instead of stalling at `Vec3::ZERO`.
+        let axis = current_up
+            .cross(target_up)
+            .try_normalize()
+            .unwrap_or_else(|| current_up.any_orthonormal_vector());
+        let error = axis * angle;
+
+        force.angular = pid.update(error, dt);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::components::upright_pid::UprightForce;
+use crate::controller::jetpack::JetpackForce;
+use crate::controller::*;
+
+/// Sums the controller's calculated forces and writes the total into the
+/// `ExternalForce` Rapier applies every physics step.
+use bevy_rapier3d::prelude::*;
+
+/// Sums [`MovementForce`], [`JumpForce`] and, where present, [`JetpackForce`]
+/// and `UprightForce` into `ExternalForce`. Each of those components is only
+/// meaningful once it is summed here. `GravityForce` and `FloatForce` are not
+/// added by this system.
+pub fn apply_controller_forces(
+    mut query: Query<(
+        &mut ExternalForce,
+        &MovementForce,
+        &JumpForce,
+        Option<&JetpackForce>,
+        Option<&UprightForce>,
+    )>,
+) {
+    for (mut external_force, movement, jump, jetpack, upright) in &mut query {
+        external_force.force =
+            movement.linear + jump.linear + jetpack.map_or(Vec3::ZERO, |jetpack| jetpack.linear);
+        external_force.torque =
+            movement.angular + upright.map_or(Vec3::ZERO, |upright| upright.angular);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::controller::*;
+
+/// Sustained-thrust flight.
+use bevy_rapier3d::prelude::*;
+
+/// A sustained-thrust flight system, as opposed to the impulsive motion of
+/// [`Jump`]. While [`ControllerInput::jumping`] is held and `fuel` remains,
+/// vertical and lateral thrust are applied toward `max_speed_up`/
+/// `max_speed_side`, and `antigravity` cancels some or all of
+/// [`GravityForce`].
+///
+/// This reuses `ControllerInput::jumping` rather than a dedicated input bit
+/// because `ControllerInput` isn't defined in this crate's tracked sources;
+/// a real deployment should give the jetpack its own channel (e.g.
+/// `ControllerInput::jetpacking`) so a single button press can't both jump
+/// and thrust at once.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct Jetpack {
+    /// Acceleration applied upwards (along `Gravity::up_vector`) while
+    /// thrusting.
+    pub accel_up: f32,
+    /// Acceleration applied sideways (perpendicular to `up_vector`, toward
+    /// `ControllerInput::movement`) while thrusting.
+    pub accel_side: f32,
+    /// The speed cap for the upward thrust.
+    pub max_speed_up: f32,
+    /// The speed cap for the sideways thrust.
+    pub max_speed_side: f32,
+    /// How much of `GravityForce` to cancel while thrusting, from `0.0`
+    /// (no cancellation) to `1.0` (full cancellation/weightlessness).
+    /// Values above `1.0` produce net upward antigravity.
+    pub antigravity: f32,
+
+    /// Current fuel remaining.
+    pub fuel: f32,
+    /// The maximum amount of fuel that can be held.
+    pub max_fuel: f32,
+    /// Fuel regained per second while grounded with the jetpack input
+    /// released.
+    pub refuel_rate: f32,
+    /// Fuel drained per second while thrusting.
+    pub fuel_use_rate: f32,
+}
+
+impl Default for Jetpack {
+    fn default() -> Self {
+        Self {
+            accel_up: 20.0,
+            accel_side: 10.0,
+            max_speed_up: 10.0,
+            max_speed_side: 10.0,
+            antigravity: 1.0,
+            fuel: 100.0,
+            max_fuel: 100.0,
+            refuel_rate: 25.0,
+            fuel_use_rate: 25.0,
+        }
+    }
+}
+
+/// Calculated force for the jetpack, accumulated into the controller's force
+/// alongside [`JumpForce`].
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct JetpackForce {
+    /// Linear impulse to apply for thrust and antigravity.
+    pub linear: Vec3,
+}
+
+/// Calculate the jetpack force for the controller.
+pub fn jetpack_force(
+    mut query: Query<(
+        &mut JetpackForce,
+        &mut Jetpack,
+        &GravityForce,
+        &ControllerInput,
+        &GroundCast,
+        &Gravity,
+        &ControllerVelocity,
+        &ControllerMass,
+    )>,
+    ctx: Res<RapierContext>,
+) {
+    let dt = ctx.integration_parameters.dt;
+    for (mut force, mut jetpack, gravity_force, input, ground_cast, gravity, velocity, mass) in
+        &mut query
+    {
+        force.linear = Vec3::ZERO;
+
+        let thrusting = input.jumping && jetpack.fuel > 0.0;
+
+        if thrusting {
+            jetpack.fuel = (jetpack.fuel - jetpack.fuel_use_rate * dt).max(0.0);
+
+            force.linear += gravity_force.linear * -jetpack.antigravity;
+
+            let up_velocity = velocity.linear.dot(gravity.up_vector);
+            let up_addspeed = jetpack.max_speed_up - up_velocity;
+            if up_addspeed > 0.0 {
+                let up_accelspeed = (jetpack.accel_up * dt).min(up_addspeed);
+                force.linear += up_accelspeed * gravity.up_vector / dt * mass.mass;
+            }
+
+            let side_dir = input.movement.try_normalize().unwrap_or(Vec3::ZERO);
+            if side_dir.length() > 0.0 {
+                let side_velocity = velocity.linear.dot(side_dir);
+                let side_addspeed = jetpack.max_speed_side - side_velocity;
+                if side_addspeed > 0.0 {
+                    let side_accelspeed = (jetpack.accel_side * dt).min(side_addspeed);
+                    force.linear += side_accelspeed * side_dir / dt * mass.mass;
+                }
+            }
+        } else if !input.jumping && ground_cast.grounded() {
+            // Only refuel once the input is actually released, not just
+            // whenever thrust stops producing force (e.g. out of fuel while
+            // the button is still held).
+            jetpack.fuel = (jetpack.fuel + jetpack.refuel_rate * dt).min(jetpack.max_fuel);
+        }
+    }
+}
@@ -22,6 +22,41 @@ pub struct Movement {
     /// If this is not `Vec3(1.0, 1.0, 1.0)` then the character can try to
     /// move up the slope.
     pub slip_force_scale: Vec3,
+
+    /// How fast the controller will get to `air_speed_limit` while airborne,
+    /// as a plain acceleration rate (unlike `acceleration`, this is not a
+    /// `Strength` — see the projected-acceleration recurrence in
+    /// `movement_force`, which multiplies this directly by `wishspeed * dt`).
+    /// Used instead of `acceleration` whenever `GroundCast` is not grounded.
+    pub air_acceleration: f32,
+    /// The `wishspeed` cap used by the airborne acceleration recurrence.
+    /// Unlike `max_speed`, this can be exceeded while `strafe` is enabled,
+    /// since the cap is only applied to the projection onto the current
+    /// `wishdir` rather than to total speed.
+    pub air_speed_limit: f32,
+    /// Enables Quake/CPM-style strafe jumping. Redirecting the movement
+    /// input while airborne can build speed past `air_speed_limit`; this is
+    /// intended emergent behavior and not a bug. When `false`, any step that
+    /// applies air acceleration clamps the resulting speed to
+    /// `air_speed_limit` instead; a step that applies none (no input, or
+    /// already at `wishspeed` along `wishdir`) leaves the speed unchanged.
+    pub strafe: bool,
+
+    /// Ground angle, in radians from `Gravity::up_vector`, below which the
+    /// character can stand with full friction and no gravity-induced slide.
+    pub min_slide_angle: f32,
+    /// Ground angle, in radians from `Gravity::up_vector`, above which the
+    /// slope is unclimbable: the uphill component of `goal_vel` is zeroed
+    /// and the character is forced to slide downhill regardless of input.
+    /// Between `min_slide_angle` and `max_walk_angle`, the character can
+    /// still walk but gravity-induced sliding is left uncancelled.
+    pub max_walk_angle: f32,
+
+    /// Opt-in ice physics. When the contacted ground's `Friction` coefficient
+    /// is below this threshold, the character bypasses the acceleration
+    /// clamp and keeps sliding instead of instantly changing direction.
+    /// `None` (the default) disables this.
+    pub slick_friction_threshold: Option<f32>,
 }
 
 #[derive(Debug, Default, Clone, Reflect)]
@@ -42,6 +77,12 @@ impl Default for Movement {
             max_speed: 10.0,
             force_scale: default(),
             slip_force_scale: Vec3::splat(1.0),
+            air_acceleration: 10.0,
+            air_speed_limit: 10.0,
+            strafe: false,
+            min_slide_angle: 35.0 * (std::f32::consts::PI / 180.0),
+            max_walk_angle: 45.0 * (std::f32::consts::PI / 180.0),
+            slick_friction_threshold: None,
         }
     }
 }
@@ -104,6 +145,52 @@ impl Cap for Vec3 {
     }
 }
 
+/// The Quake/CPM-style projected-acceleration recurrence used by
+/// [`movement_force`] while airborne. The cap (`addspeed`) is applied to the
+/// projection onto `wishdir`, not to total speed, which is what lets
+/// strafing build speed past `wishspeed`.
+#[allow(clippy::too_many_arguments)]
+fn air_movement_force(
+    relative_velocity: Vec3,
+    wishdir: Vec3,
+    wishspeed: f32,
+    air_acceleration: f32,
+    air_speed_limit: f32,
+    strafe: bool,
+    mass: f32,
+    dt: f32,
+) -> Vec3 {
+    let current = relative_velocity.dot(wishdir);
+    let addspeed = wishspeed - current;
+
+    if addspeed <= 0.0 {
+        return Vec3::ZERO;
+    }
+
+    let accelspeed = (air_acceleration * wishspeed * dt).min(addspeed);
+    let mut air_force = accelspeed * wishdir / dt * mass;
+
+    if !strafe {
+        // Without strafing, clamp the resulting speed to `air_speed_limit`
+        // instead of allowing it to build up across direction changes.
+        let new_relative_velocity = relative_velocity + air_force / mass * dt;
+        if new_relative_velocity.length() > air_speed_limit {
+            let capped = new_relative_velocity.clamp_length_max(air_speed_limit);
+            air_force = (capped - relative_velocity) * mass / dt;
+        }
+    }
+
+    air_force
+}
+
+/// Clamps `current_up_speed` into `[cap_min, cap_max]`, tolerating a
+/// misconfigured `cap_min > cap_max` (unlike `f32::clamp`, which would panic)
+/// by treating `cap_min` as authoritative.
+fn clamp_jump_speed(current_up_speed: f32, cap_min: f32, cap_max: f32) -> f32 {
+    let cap_max = cap_max.max(cap_min);
+    current_up_speed.max(cap_min).min(cap_max)
+}
+
 /// Calculates the movement forces for this controller.
 pub fn movement_force(
     ctx: Res<RapierContext>,
@@ -118,6 +205,7 @@ pub fn movement_force(
         &GroundCaster,
         &ControllerVelocity,
         &ControllerMass,
+        &Jump,
     )>,
     frictions: Query<&Friction>,
     mut gizmos: Gizmos,
@@ -134,6 +222,7 @@ pub fn movement_force(
         ground_caster,
         velocity,
         mass,
+        jumping,
     ) in &mut query
     {
         force.linear = Vec3::ZERO;
@@ -144,7 +233,7 @@ pub fn movement_force(
         let input_goal_vel = input_dir * movement.max_speed;
         let mut goal_vel = input_goal_vel;
 
-        let slip_force = match cast.current {
+        let mut slip_force = match cast.current {
             Some(ground) if !ground.stable => {
                 let (x, z) = ground.cast.normal.any_orthonormal_pair();
                 gizmos.ray(ground.cast.point, ground.cast.normal, Color::BLUE);
@@ -172,6 +261,54 @@ pub fn movement_force(
             _ => None,
         };
 
+        // Classify the current ground contact by its angle from `up_vector`.
+        // Below `min_slide_angle` the character stands still with full
+        // friction, so any gravity-induced slide computed above is
+        // cancelled outright. Between `min_slide_angle` and
+        // `max_walk_angle` the character can still walk, but that slide is
+        // left uncancelled. Above `max_walk_angle` the slope is
+        // unclimbable: the uphill component of `goal_vel` is zeroed and a
+        // downhill slide is forced regardless of input.
+        if let Some(ground) = cast.current {
+            let ground_angle = ground
+                .cast
+                .normal
+                .dot(gravity.up_vector)
+                .clamp(-1.0, 1.0)
+                .acos();
+
+            if ground_angle > movement.max_walk_angle {
+                let (x, z) = ground.cast.normal.any_orthonormal_pair();
+                let projected_x = gravity.up_vector.project_onto(x);
+                let projected_z = gravity.up_vector.project_onto(z);
+                let downhill_vector = -(projected_x + projected_z) * force_scale;
+
+                if downhill_vector.length() > 0.01 {
+                    let downhill_dir = downhill_vector.normalize();
+
+                    // Zero out any uphill component of goal_vel entirely.
+                    let uphill_component = goal_vel.dot(downhill_dir);
+                    if uphill_component < 0.0 {
+                        goal_vel -= uphill_component * downhill_dir;
+                    }
+
+                    // Force a downhill slide regardless of input. `goal_vel`
+                    // alone only affects the force *cap*, not the driving
+                    // force (which is zero with no input), so also emit an
+                    // explicit slide force here, mirroring the unstable-ground
+                    // `slip_force` above (which holds the uphill direction and
+                    // is subtracted), so the slide fires even when the
+                    // player gives no input on an otherwise-stable slope.
+                    goal_vel += downhill_dir * movement.max_speed;
+                    slip_force = Some(-downhill_dir);
+                }
+            } else if ground_angle <= movement.min_slide_angle {
+                // Shallow enough to stand: full friction holds the
+                // character, so cancel any gravity-induced slide entirely.
+                slip_force = None;
+            }
+        }
+
         let last_ground_vel = if let Some(ground) = cast.viable.last() {
             ground.point_velocity
         } else {
@@ -179,6 +316,7 @@ pub fn movement_force(
         };
 
         let relative_velocity = (velocity.linear - last_ground_vel.linvel) * force_scale;
+        let mut slick = false;
         let friction_force = if let ViableGround::Ground(ground) = cast.viable {
             let friction = frictions
                 .get(controller_entity)
@@ -188,7 +326,27 @@ pub fn movement_force(
                 .get(ground.entity)
                 .copied()
                 .unwrap_or(Friction::default());
-            let friction_coefficient = friction.coefficient.max(ground_friction.coefficient);
+            let mut friction_coefficient = friction.coefficient.max(ground_friction.coefficient);
+
+            // Ice physics: ground with friction below `slick_friction_threshold`
+            // bypasses the acceleration clamp below, so the character keeps
+            // sliding and cannot instantly change direction.
+            if let Some(threshold) = movement.slick_friction_threshold {
+                if ground_friction.coefficient < threshold {
+                    slick = true;
+                    friction_coefficient = ground_friction.coefficient;
+                }
+            }
+
+            // For a short window after landing, boost friction so the
+            // character "sticks" instead of sliding out, decaying back to
+            // normal as `landing_friction_timer` ticks down.
+            if jumping.landing_friction_duration > 0.0 {
+                let landing_t =
+                    (jumping.landing_friction_timer / jumping.landing_friction_duration).clamp(0.0, 1.0);
+                friction_coefficient *= 1.0 + (jumping.landing_friction_boost - 1.0) * landing_t;
+            }
+
             friction_coefficient * relative_velocity * mass.mass / dt
         } else {
             Vec3::ZERO
@@ -199,25 +357,52 @@ pub fn movement_force(
         // Debug check to make sure we can clamp by the instant force
         //assert!((-instant_force).cmple(instant_force).all());
 
-        let strength = movement.acceleration.get(mass.mass, dt);
+        let grounded = matches!(cast.viable, ViableGround::Ground(_));
+
+        let movement_force = if !grounded {
+            // Classic Quake/CPM projected-acceleration recurrence: the cap is
+            // on the projection onto `wishdir`, not on total speed, so
+            // rotating `wishdir` while airborne (strafing) can build speed
+            // past `wishspeed` when `strafe` is enabled.
+            let wishdir = input_dir.try_normalize().unwrap_or(Vec3::ZERO);
+            let wishspeed = input_goal_vel.length().min(movement.air_speed_limit);
+
+            air_movement_force(
+                relative_velocity,
+                wishdir,
+                wishspeed,
+                movement.air_acceleration,
+                movement.air_speed_limit,
+                movement.strafe,
+                mass.mass,
+                dt,
+            ) * force_scale
+        } else {
+            let strength = movement.acceleration.get(mass.mass, dt);
 
-        // This is effectively an implicit spring-damper function since the displacement is the velocity.
-        // We could try to add a damping factor here based off acceleration, but I'm not sure it matters.
-        let mut movement_force = (input_goal_vel * strength * force_scale);
+            // This is effectively an implicit spring-damper function since the displacement is the velocity.
+            // We could try to add a damping factor here based off acceleration, but I'm not sure it matters.
+            let movement_force = (input_goal_vel * strength * force_scale);
 
-        // get displacement of relative velocity to goal velocity
-        let clamped_velocity = relative_velocity.cap(goal_vel);
+            if slick {
+                // Ice physics: don't clamp by the displacement-to-goal force,
+                // so the character can't instantly change direction.
+                movement_force
+            } else {
+                // get displacement of relative velocity to goal velocity
+                let clamped_velocity = relative_velocity.cap(goal_vel);
 
-        let displacement = goal_vel - clamped_velocity;
-        let max_movement_force = displacement * mass.mass / dt * force_scale + friction_force;
-        let movement_force = movement_force.cap(max_movement_force);
+                let displacement = goal_vel - clamped_velocity;
+                let max_movement_force =
+                    displacement * mass.mass / dt * force_scale + friction_force;
+                movement_force.cap(max_movement_force)
+            }
+        };
 
         if movement_force.length() > 0.1 {
-            info!("displacement: {:.1?}", displacement);
             info!("relative_vel: {:.1?}", relative_velocity);
             info!("goal_vel: {:.1?}", goal_vel);
             info!("movement_force: {:.1?}", movement_force);
-            info!("max_movement_force: {:.1?}", max_movement_force);
         }
 
         force.linear += movement_force - friction_force - slip_force.unwrap_or(Vec3::ZERO);
@@ -273,6 +458,33 @@ pub struct Jump {
     /// How long to skip ground checks after jumping. Usually this should be set just high enough that the character is out of range of the ground
     /// just before the timer elapses.
     pub skip_ground_check_duration: f32,
+
+    /// The minimum up-velocity the character is left with just before the
+    /// jump impulse is added on top of it. With the default of `0.0`
+    /// (equal to `jump_speed_cap_max`), this reproduces the old behavior of
+    /// unconditionally negating existing up-velocity before jumping.
+    pub jump_speed_cap_min: f32,
+    /// The maximum up-velocity the character is left with just before the
+    /// jump impulse is added on top of it.
+    pub jump_speed_cap_max: f32,
+    /// When `true`, the cap is skipped if the last viable ground normal is
+    /// tilted beyond a small threshold off `up_vector`, so uphill momentum
+    /// from running up a ramp carries into jump height ("ramp jumping").
+    pub disable_cap_on_ramps: bool,
+
+    /// Multiplier applied to friction for `landing_friction_duration`
+    /// seconds after landing, so the character "sticks" instead of sliding
+    /// out. `1.0` (the default) disables this.
+    pub landing_friction_boost: f32,
+    /// How long, in seconds, `landing_friction_boost` applies after
+    /// `grounded` transitions from `false` to `true`. `0.0` (the default)
+    /// disables this.
+    pub landing_friction_duration: f32,
+    /// Timer tracking `landing_friction_duration`.
+    pub landing_friction_timer: f32,
+    /// Was the controller grounded last frame. Used to detect the
+    /// false-to-true transition that starts `landing_friction_timer`.
+    pub was_grounded: bool,
 }
 
 impl Default for Jump {
@@ -299,6 +511,15 @@ impl Default for Jump {
             pressed_last_frame: false,
 
             skip_ground_check_duration: 0.3,
+
+            jump_speed_cap_min: 0.0,
+            jump_speed_cap_max: 0.0,
+            disable_cap_on_ramps: false,
+
+            landing_friction_boost: 1.0,
+            landing_friction_duration: 0.0,
+            landing_friction_timer: 0.0,
+            was_grounded: false,
         }
     }
 }
@@ -316,6 +537,7 @@ impl Jump {
         tick(&mut self.jump_timer);
         tick(&mut self.buffer_timer);
         tick(&mut self.coyote_duration);
+        tick(&mut self.landing_friction_timer);
     }
 
     /// Are we currently jumping?
@@ -379,6 +601,8 @@ pub fn jump_force(
         &Gravity,
         &ControllerVelocity,
         &ControllerMass,
+        Option<&mut WallCaster>,
+        Option<&WallCast>,
     )>,
     ctx: Res<RapierContext>,
 ) {
@@ -395,6 +619,8 @@ pub fn jump_force(
         gravity,
         velocity,
         mass,
+        wall_caster,
+        wall_cast,
     ) in &mut query
     {
         force.linear = Vec3::ZERO;
@@ -406,6 +632,11 @@ pub fn jump_force(
             jumping.coyote_timer = jumping.coyote_duration;
         }
 
+        if grounded && !jumping.was_grounded {
+            jumping.landing_friction_timer = jumping.landing_friction_duration;
+        }
+        jumping.was_grounded = grounded;
+
         if jumping.cooldown_timer <= 0.0 && grounded {
             jumping.reset_jump();
         }
@@ -424,13 +655,38 @@ pub fn jump_force(
             jumping.buffer_timer = jumping.buffer_duration;
         }
 
+        let wall_in_range = wall_caster.as_deref().zip(wall_cast).and_then(|(caster, cast)| {
+            cast.current
+                .filter(|wall| wall.distance <= caster.max_wall_jump_distance)
+        });
+
         if jumping.can_jump(grounded) && just_jumped {
-            // Negating the current velocity increases consistency for falling jumps,
-            // and prevents stacking jumps to reach high upwards velocities
+            // Clamping (rather than unconditionally negating) the current
+            // velocity increases consistency for falling jumps and prevents
+            // stacking jumps to reach high upwards velocities, while still
+            // allowing some existing up-velocity to carry into the jump.
             let initial_jump_force = jumping.initial_force * gravity.up_vector;
-            let negate_up_velocity =
-                (-1.0 * gravity.up_vector * velocity.dot(gravity.up_vector)) * mass.mass / dt;
-            force.linear += negate_up_velocity + initial_jump_force;
+
+            // A normal tilted more than ~2.5 degrees off `up_vector` counts as a ramp.
+            const RAMP_NORMAL_THRESHOLD: f32 = 0.999;
+            let on_ramp = ground_cast
+                .viable
+                .last()
+                .is_some_and(|ground| ground.cast.normal.dot(gravity.up_vector) < RAMP_NORMAL_THRESHOLD);
+
+            let up_velocity_force = if jumping.disable_cap_on_ramps && on_ramp {
+                Vec3::ZERO
+            } else {
+                let current_up_speed = velocity.dot(gravity.up_vector);
+                let capped_up_speed = clamp_jump_speed(
+                    current_up_speed,
+                    jumping.jump_speed_cap_min,
+                    jumping.jump_speed_cap_max,
+                );
+                (gravity.up_vector * (capped_up_speed - current_up_speed)) * mass.mass / dt
+            };
+
+            force.linear += up_velocity_force + initial_jump_force;
 
             gravity_force.linear = Vec3::ZERO;
             float_force.linear = Vec3::ZERO;
@@ -439,6 +695,24 @@ pub fn jump_force(
             jumping.cooldown_timer = jumping.cooldown_duration;
 
             jumping.jump_timer = jumping.jump_duration;
+        // `can_jump` failed for ground reasons (airborne, out of jumps), but
+        // there's a wall in range: fire a wall-jump instead.
+        } else if !grounded && just_jumped {
+            if let (Some(wall), Some(wall_caster)) = (wall_in_range, wall_caster.as_deref()) {
+                let wall_jump_dir = (gravity.up_vector * wall_caster.wall_jump_up_ratio
+                    + wall.normal * (1.0 - wall_caster.wall_jump_up_ratio))
+                    .try_normalize()
+                    .unwrap_or(gravity.up_vector);
+
+                force.linear += wall_jump_dir * wall_caster.wall_jump_force * mass.mass / dt;
+
+                gravity_force.linear = Vec3::ZERO;
+                float_force.linear = Vec3::ZERO;
+
+                jumping.remaining_jumps = jumping.jumps;
+                jumping.cooldown_timer = jumping.cooldown_duration;
+                jumping.jump_timer = jumping.jump_duration;
+            }
         // don't double up on initial force and jumping forces.
         } else if jumping.jumping() {
             if !input.jumping {
@@ -455,5 +729,78 @@ pub fn jump_force(
         }
 
         jumping.pressed_last_frame = input.jumping;
+
+        // Wall-running: while airborne, touching a wall, and moving fast
+        // enough horizontally, reduce gravity along `up_vector` so the
+        // character slides along the wall. The timer is refreshed every frame
+        // those conditions hold, so gravity stays reduced for as long as they
+        // hold and for up to `wall_run_duration` after they stop.
+        if let Some(mut wall_caster) = wall_caster {
+            if wall_caster.wall_run_timer > 0.0 {
+                wall_caster.wall_run_timer = (wall_caster.wall_run_timer - dt).max(0.0);
+            }
+
+            if wall_caster.wall_run_enabled && !grounded {
+                let horizontal_speed =
+                    (velocity - velocity.project_onto(gravity.up_vector)).length();
+                if wall_in_range.is_some() && horizontal_speed >= wall_caster.wall_run_min_speed {
+                    wall_caster.wall_run_timer = wall_caster.wall_run_duration;
+                }
+            }
+
+            if wall_caster.wall_run_timer > 0.0 {
+                gravity_force.linear *= 1.0 - wall_caster.wall_run_gravity_scale;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn air_movement_force_applies_no_force_once_at_wishspeed() {
+        let force = air_movement_force(Vec3::X * 10.0, Vec3::X, 10.0, 10.0, 10.0, false, 1.0, 1.0 / 60.0);
+        assert_eq!(force, Vec3::ZERO);
+    }
+
+    #[test]
+    fn air_movement_force_accelerates_gradually_not_instantly() {
+        // With a small air_acceleration, a single step should only close a
+        // fraction of the gap to wishspeed, not snap straight to it.
+        let force = air_movement_force(Vec3::ZERO, Vec3::X, 10.0, 1.0, 10.0, false, 1.0, 1.0 / 60.0);
+        let dt = 1.0 / 60.0;
+        // accelspeed = min(air_acceleration * wishspeed * dt, addspeed)
+        let expected_accelspeed: f32 = (1.0_f32 * 10.0 * dt).min(10.0);
+        let expected_force = expected_accelspeed * Vec3::X / dt;
+        assert!((force - expected_force).length() < 1e-4);
+        assert!(expected_accelspeed < 10.0, "a single step should not reach wishspeed");
+    }
+
+    #[test]
+    fn air_movement_force_without_strafe_caps_total_speed() {
+        // Redirecting wishdir at a high air_acceleration should not be able
+        // to push the resulting speed past air_speed_limit when strafing is
+        // disabled.
+        let relative_velocity = Vec3::X * 9.9;
+        let wishdir = Vec3::Z;
+        let force = air_movement_force(relative_velocity, wishdir, 10.0, 1000.0, 10.0, false, 1.0, 1.0 / 60.0);
+        let dt = 1.0 / 60.0;
+        let new_velocity = relative_velocity + force * dt;
+        assert!(new_velocity.length() <= 10.0 + 1e-4);
+    }
+
+    #[test]
+    fn clamp_jump_speed_respects_range() {
+        assert_eq!(clamp_jump_speed(5.0, 0.0, 0.0), 0.0);
+        assert_eq!(clamp_jump_speed(-5.0, -2.0, 2.0), -2.0);
+        assert_eq!(clamp_jump_speed(1.0, -2.0, 2.0), 1.0);
+    }
+
+    #[test]
+    fn clamp_jump_speed_does_not_panic_when_min_exceeds_max() {
+        // f32::clamp would panic here; this should not.
+        assert_eq!(clamp_jump_speed(5.0, 3.0, 1.0), 3.0);
     }
 }
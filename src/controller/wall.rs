@@ -0,0 +1,124 @@
+use crate::controller::*;
+
+/// Lateral wall sensing, analogous to [`GroundCaster`]/[`GroundCast`], used
+/// for wall-jumping and wall-running.
+use bevy_rapier3d::prelude::*;
+
+/// Settings for casting horizontally for nearby walls.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct WallCaster {
+    /// How far, horizontally, to cast for a wall.
+    pub cast_length: f32,
+    /// How many rays to cast around the controller, evenly spaced
+    /// (perpendicular to `Gravity::up_vector`).
+    pub ray_count: u32,
+    /// Entities to ignore when casting for walls, e.g. the character itself.
+    pub exclude_from_wall: HashSet<Entity>,
+
+    /// The maximum wall distance a wall-jump can be triggered from.
+    pub max_wall_jump_distance: f32,
+    /// The strength of the impulse applied on a wall-jump.
+    pub wall_jump_force: f32,
+    /// How much of the wall-jump impulse goes toward `up_vector`, from `0.0`
+    /// (straight off the wall) to `1.0` (straight up). The remainder is
+    /// applied along the wall's normal.
+    pub wall_jump_up_ratio: f32,
+
+    /// Enables wall-running: while airborne, touching a wall, and moving
+    /// horizontally at least `wall_run_min_speed`, gravity along
+    /// `up_vector` is reduced, and stays reduced for up to
+    /// `wall_run_duration` after those conditions stop holding.
+    pub wall_run_enabled: bool,
+    /// The minimum horizontal speed required to start or refresh a wall-run.
+    pub wall_run_min_speed: f32,
+    /// How much to scale down gravity while wall-running, from `0.0` (no
+    /// change) to `1.0` (gravity fully cancelled).
+    pub wall_run_gravity_scale: f32,
+    /// How long gravity stays reduced after the wall-run conditions last
+    /// held. The timer is refreshed every frame they hold, so this does not
+    /// bound the length of a wall-run along a continuous wall.
+    pub wall_run_duration: f32,
+    /// Timer tracking `wall_run_duration`.
+    pub wall_run_timer: f32,
+}
+
+impl Default for WallCaster {
+    fn default() -> Self {
+        Self {
+            cast_length: 0.6,
+            ray_count: 8,
+            exclude_from_wall: default(),
+
+            max_wall_jump_distance: 0.6,
+            wall_jump_force: 12.0,
+            wall_jump_up_ratio: 0.6,
+
+            wall_run_enabled: false,
+            wall_run_min_speed: 4.0,
+            wall_run_gravity_scale: 0.8,
+            wall_run_duration: 0.8,
+            wall_run_timer: 0.0,
+        }
+    }
+}
+
+/// A single wall contact found by [`wall_cast`].
+#[derive(Debug, Clone, Copy, Reflect)]
+pub struct WallContact {
+    /// The entity of the wall that was hit.
+    pub entity: Entity,
+    /// The surface normal of the wall at the hit point.
+    pub normal: Vec3,
+    /// The world-space point that was hit.
+    pub point: Vec3,
+    /// The distance from the controller to the hit point.
+    pub distance: f32,
+}
+
+/// The nearest wall contact found this frame, if any.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct WallCast {
+    /// The nearest wall within `WallCaster::cast_length`, if any.
+    pub current: Option<WallContact>,
+}
+
+/// Cast horizontally around the controller for nearby walls.
+pub fn wall_cast(
+    ctx: Res<RapierContext>,
+    mut query: Query<(Entity, &GlobalTransform, &mut WallCast, &WallCaster, &Gravity)>,
+) {
+    for (entity, global, mut wall_cast, caster, gravity) in &mut query {
+        let origin = global.translation();
+        let up = gravity.up_vector.try_normalize().unwrap_or(Vec3::Y);
+        let (x, z) = up.any_orthonormal_pair();
+
+        let ray_count = caster.ray_count.max(1);
+        let mut nearest: Option<WallContact> = None;
+
+        for i in 0..ray_count {
+            let angle = i as f32 / ray_count as f32 * std::f32::consts::TAU;
+            let dir = x * angle.cos() + z * angle.sin();
+
+            let filter = QueryFilter::default()
+                .exclude_collider(entity)
+                .predicate(&|hit_entity| !caster.exclude_from_wall.contains(&hit_entity));
+
+            if let Some((hit_entity, hit)) =
+                ctx.cast_ray_and_get_normal(origin, dir, caster.cast_length, true, filter)
+            {
+                if nearest.map_or(true, |contact| hit.toi < contact.distance) {
+                    nearest = Some(WallContact {
+                        entity: hit_entity,
+                        normal: hit.normal,
+                        point: hit.point,
+                        distance: hit.toi,
+                    });
+                }
+            }
+        }
+
+        wall_cast.current = nearest;
+    }
+}